@@ -3,15 +3,24 @@ use js_sys::Uint8Array;
 use web_sys::console;
 
 // Import post-quantum cryptography using NIST FIPS standards
-use fips203::{ml_kem_768, traits::{KeyGen, Encaps, Decaps, SerDes}};
-use fips204::{ml_dsa_65, traits::{KeyGen as DsaKeyGen, Signer, Verifier, SerDes as DsaSerDes}};
+use fips203::{ml_kem_512, ml_kem_768, ml_kem_1024, traits::{KeyGen, Encaps, Decaps, SerDes}};
+use fips204::{ml_dsa_44, ml_dsa_65, ml_dsa_87, traits::{KeyGen as DsaKeyGen, Signer, Verifier, SerDes as DsaSerDes}};
 use sha3::{Digest, Sha3_256};
 use rand::{SeedableRng};
 use rand_chacha::ChaCha20Rng;
 
+// Classical ECDH half of the hybrid KEM
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
+
 // Random number generation
 use getrandom::getrandom;
 
+// Secret-key material is wiped on drop instead of lingering in WASM linear memory
+use zeroize::{Zeroize, Zeroizing};
+
+// Self-describing envelope transport encoding (base64url, no padding)
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global allocator
 #[cfg(feature = "wee_alloc")]
 #[global_allocator]
@@ -24,241 +33,1143 @@ pub fn main() {
     console_error_panic_hook::set_once();
 }
 
-// Volly KEM (Key Encapsulation Mechanism) using ML-KEM-768
+/// Identifies which ML-KEM parameter set a `VollyKEM` instance negotiates.
+///
+/// The discriminant doubles as the suite-id byte that gets prefixed onto
+/// every exported public key, secret key and ciphertext so the right
+/// parameter set can be auto-detected on import instead of guessed from length.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KemSuite {
+    MlKem512 = 0,
+    MlKem768 = 1,
+    MlKem1024 = 2,
+}
+
+impl KemSuite {
+    fn from_name(name: &str) -> Result<Self, JsValue> {
+        match name {
+            "ML-KEM-512" => Ok(KemSuite::MlKem512),
+            "ML-KEM-768" => Ok(KemSuite::MlKem768),
+            "ML-KEM-1024" => Ok(KemSuite::MlKem1024),
+            other => Err(JsValue::from_str(&format!("Unknown KEM suite: {}", other))),
+        }
+    }
+
+    fn from_id(id: u8) -> Result<Self, JsValue> {
+        match id {
+            0 => Ok(KemSuite::MlKem512),
+            1 => Ok(KemSuite::MlKem768),
+            2 => Ok(KemSuite::MlKem1024),
+            other => Err(JsValue::from_str(&format!("Unknown KEM suite id: {}", other))),
+        }
+    }
+
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            KemSuite::MlKem512 => "ML-KEM-512",
+            KemSuite::MlKem768 => "ML-KEM-768",
+            KemSuite::MlKem1024 => "ML-KEM-1024",
+        }
+    }
+
+    fn security_level(self) -> &'static str {
+        match self {
+            KemSuite::MlKem512 => "Level 1 (128-bit post-quantum)",
+            KemSuite::MlKem768 => "Level 3 (192-bit post-quantum)",
+            KemSuite::MlKem1024 => "Level 5 (256-bit post-quantum)",
+        }
+    }
+
+    fn ek_len(self) -> usize {
+        match self {
+            KemSuite::MlKem512 => ml_kem_512::EK_LEN,
+            KemSuite::MlKem768 => ml_kem_768::EK_LEN,
+            KemSuite::MlKem1024 => ml_kem_1024::EK_LEN,
+        }
+    }
+
+    fn dk_len(self) -> usize {
+        match self {
+            KemSuite::MlKem512 => ml_kem_512::DK_LEN,
+            KemSuite::MlKem768 => ml_kem_768::DK_LEN,
+            KemSuite::MlKem1024 => ml_kem_1024::DK_LEN,
+        }
+    }
+
+    fn ct_len(self) -> usize {
+        match self {
+            KemSuite::MlKem512 => ml_kem_512::CT_LEN,
+            KemSuite::MlKem768 => ml_kem_768::CT_LEN,
+            KemSuite::MlKem1024 => ml_kem_1024::CT_LEN,
+        }
+    }
+}
+
+/// Identifies which ML-DSA parameter set a `VollyDSA` instance negotiates.
+///
+/// Like `KemSuite`, the discriminant is the suite-id byte prefixed onto
+/// exported keys so importers can auto-detect the parameter set.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DsaSuite {
+    MlDsa44 = 0,
+    MlDsa65 = 1,
+    MlDsa87 = 2,
+}
+
+impl DsaSuite {
+    fn from_name(name: &str) -> Result<Self, JsValue> {
+        match name {
+            "ML-DSA-44" => Ok(DsaSuite::MlDsa44),
+            "ML-DSA-65" => Ok(DsaSuite::MlDsa65),
+            "ML-DSA-87" => Ok(DsaSuite::MlDsa87),
+            other => Err(JsValue::from_str(&format!("Unknown DSA suite: {}", other))),
+        }
+    }
+
+    fn from_id(id: u8) -> Result<Self, JsValue> {
+        match id {
+            0 => Ok(DsaSuite::MlDsa44),
+            1 => Ok(DsaSuite::MlDsa65),
+            2 => Ok(DsaSuite::MlDsa87),
+            other => Err(JsValue::from_str(&format!("Unknown DSA suite id: {}", other))),
+        }
+    }
+
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DsaSuite::MlDsa44 => "ML-DSA-44",
+            DsaSuite::MlDsa65 => "ML-DSA-65",
+            DsaSuite::MlDsa87 => "ML-DSA-87",
+        }
+    }
+
+    fn security_level(self) -> &'static str {
+        match self {
+            DsaSuite::MlDsa44 => "Level 2 (128-bit post-quantum)",
+            DsaSuite::MlDsa65 => "Level 3 (192-bit post-quantum)",
+            DsaSuite::MlDsa87 => "Level 5 (256-bit post-quantum)",
+        }
+    }
+
+    fn pk_len(self) -> usize {
+        match self {
+            DsaSuite::MlDsa44 => ml_dsa_44::PK_LEN,
+            DsaSuite::MlDsa65 => ml_dsa_65::PK_LEN,
+            DsaSuite::MlDsa87 => ml_dsa_87::PK_LEN,
+        }
+    }
+
+    fn sk_len(self) -> usize {
+        match self {
+            DsaSuite::MlDsa44 => ml_dsa_44::SK_LEN,
+            DsaSuite::MlDsa65 => ml_dsa_65::SK_LEN,
+            DsaSuite::MlDsa87 => ml_dsa_87::SK_LEN,
+        }
+    }
+
+    fn sig_len(self) -> usize {
+        match self {
+            DsaSuite::MlDsa44 => ml_dsa_44::SIG_LEN,
+            DsaSuite::MlDsa65 => ml_dsa_65::SIG_LEN,
+            DsaSuite::MlDsa87 => ml_dsa_87::SIG_LEN,
+        }
+    }
+}
+
+/// Identifies what kind of artifact a `VollyEnvelope` carries, so import methods can
+/// reject e.g. a ciphertext that's been handed to a public-key parameter.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArtifactKind {
+    KemPublicKey = 0,
+    KemSecretKey = 1,
+    KemCiphertext = 2,
+    DsaPublicKey = 3,
+    DsaSecretKey = 4,
+    DsaSignature = 5,
+    HybridKemPublicKey = 6,
+    HybridKemSecretKey = 7,
+    HybridKemCiphertext = 8,
+}
+
+impl ArtifactKind {
+    fn from_id(id: u8) -> Result<Self, JsValue> {
+        match id {
+            0 => Ok(ArtifactKind::KemPublicKey),
+            1 => Ok(ArtifactKind::KemSecretKey),
+            2 => Ok(ArtifactKind::KemCiphertext),
+            3 => Ok(ArtifactKind::DsaPublicKey),
+            4 => Ok(ArtifactKind::DsaSecretKey),
+            5 => Ok(ArtifactKind::DsaSignature),
+            6 => Ok(ArtifactKind::HybridKemPublicKey),
+            7 => Ok(ArtifactKind::HybridKemSecretKey),
+            8 => Ok(ArtifactKind::HybridKemCiphertext),
+            other => Err(JsValue::from_str(&format!("Unknown artifact kind id: {}", other))),
+        }
+    }
+
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ArtifactKind::KemPublicKey => "KemPublicKey",
+            ArtifactKind::KemSecretKey => "KemSecretKey",
+            ArtifactKind::KemCiphertext => "KemCiphertext",
+            ArtifactKind::DsaPublicKey => "DsaPublicKey",
+            ArtifactKind::DsaSecretKey => "DsaSecretKey",
+            ArtifactKind::DsaSignature => "DsaSignature",
+            ArtifactKind::HybridKemPublicKey => "HybridKemPublicKey",
+            ArtifactKind::HybridKemSecretKey => "HybridKemSecretKey",
+            ArtifactKind::HybridKemCiphertext => "HybridKemCiphertext",
+        }
+    }
+}
+
+/// Suite id used to tag hybrid-KEM envelopes, which don't negotiate a `KemSuite`/`DsaSuite`
+/// (there's only one hybrid parameter set: ML-KEM-768 + X25519).
+const HYBRID_SUITE_ID: u8 = 0;
+
+/// Magic bytes identifying a Volly envelope, checked before anything else on import.
+const ENVELOPE_MAGIC: [u8; 4] = *b"VLY1";
+
+/// Current envelope wire-format version.
+const ENVELOPE_VERSION: u8 = 1;
+
+/// Self-describing wrapper around an exported key, ciphertext, or signature: magic bytes,
+/// an `ArtifactKind` tag, a suite-id byte, a format version, and the raw payload. Wrapping
+/// artifacts this way means a ciphertext can never be silently parsed as a public key, and a
+/// suite mismatch is caught with a clear error instead of failing deep inside a length check.
+#[wasm_bindgen]
+pub struct VollyEnvelope {
+    kind: ArtifactKind,
+    suite_id: u8,
+    version: u8,
+    payload: Zeroizing<Vec<u8>>,
+}
+
+impl VollyEnvelope {
+    fn wrap(kind: ArtifactKind, suite_id: u8, payload: Vec<u8>) -> VollyEnvelope {
+        VollyEnvelope { kind, suite_id, version: ENVELOPE_VERSION, payload: Zeroizing::new(payload) }
+    }
+
+    /// Whether `kind` carries secret-key material, and so must not be handed out through the
+    /// plain `payload` getter.
+    fn is_secret_kind(kind: ArtifactKind) -> bool {
+        matches!(kind, ArtifactKind::KemSecretKey | ArtifactKind::DsaSecretKey | ArtifactKind::HybridKemSecretKey)
+    }
+
+    /// Check the envelope carries the expected kind and suite, returning its payload.
+    fn expect(&self, kind: ArtifactKind, suite_id: u8) -> Result<&[u8], JsValue> {
+        if self.kind != kind {
+            return Err(JsValue::from_str(&format!(
+                "Envelope type mismatch: expected {}, got {}",
+                kind.name(),
+                self.kind.name()
+            )));
+        }
+        if self.suite_id != suite_id {
+            return Err(JsValue::from_str(&format!(
+                "Envelope suite mismatch: expected suite id {}, got {}",
+                suite_id, self.suite_id
+            )));
+        }
+        Ok(&self.payload[..])
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(ENVELOPE_MAGIC.len() + 3 + self.payload.len());
+        out.extend_from_slice(&ENVELOPE_MAGIC);
+        out.push(self.kind.id());
+        out.push(self.suite_id);
+        out.push(self.version);
+        out.extend_from_slice(&self.payload);
+        out
+    }
+}
+
+#[wasm_bindgen]
+impl VollyEnvelope {
+    /// The kind of artifact this envelope carries
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> ArtifactKind {
+        self.kind
+    }
+
+    /// The suite id this artifact was produced under
+    #[wasm_bindgen(getter)]
+    pub fn suite_id(&self) -> u8 {
+        self.suite_id
+    }
+
+    /// The envelope format version
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// The raw artifact bytes, without the envelope header. Refuses secret-key kinds so a
+    /// `KemSecretKey`/`DsaSecretKey`/`HybridKemSecretKey` envelope can't leak its payload through
+    /// a plain property read — call `export_payload()` to opt in instead.
+    #[wasm_bindgen(getter)]
+    pub fn payload(&self) -> Result<Uint8Array, JsValue> {
+        if Self::is_secret_kind(self.kind) {
+            return Err(JsValue::from_str(&format!(
+                "{} payload is secret key material; call export_payload() to opt in",
+                self.kind.name()
+            )));
+        }
+        Ok(Uint8Array::from(&self.payload[..]))
+    }
+
+    /// Explicitly opt in to reading the raw payload, including secret-key material.
+    /// Not a property getter on purpose, so callers can't pull key bytes into GC memory by
+    /// accident; mirrors `export_secret_key()` on `VollyKEM`/`VollyDSA`/`VollyHybridKEM`.
+    #[wasm_bindgen]
+    pub fn export_payload(&self) -> Uint8Array {
+        Uint8Array::from(&self.payload[..])
+    }
+
+    /// Scrub the payload from memory immediately instead of waiting for drop
+    #[wasm_bindgen]
+    pub fn destroy(&mut self) {
+        self.payload.zeroize();
+    }
+
+    /// Serialize to the wire format: magic || kind || suite id || version || payload
+    #[wasm_bindgen]
+    pub fn to_bytes(&self) -> Uint8Array {
+        Uint8Array::from(&self.encode()[..])
+    }
+
+    /// Parse a wire-format envelope produced by `to_bytes`/`to_base64url`
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8]) -> Result<VollyEnvelope, JsValue> {
+        let header_len = ENVELOPE_MAGIC.len() + 3;
+        if bytes.len() < header_len {
+            return Err(JsValue::from_str(&format!("Envelope too short: expected at least {} bytes, got {}", header_len, bytes.len())));
+        }
+        if bytes[..ENVELOPE_MAGIC.len()] != ENVELOPE_MAGIC {
+            return Err(JsValue::from_str("Not a Volly envelope: bad magic bytes"));
+        }
+        let kind = ArtifactKind::from_id(bytes[4])?;
+        let suite_id = bytes[5];
+        let version = bytes[6];
+        if version != ENVELOPE_VERSION {
+            return Err(JsValue::from_str(&format!("Unsupported envelope version: {}", version)));
+        }
+        Ok(VollyEnvelope { kind, suite_id, version, payload: Zeroizing::new(bytes[header_len..].to_vec()) })
+    }
+
+    /// Serialize to a base64url (no padding) string, safe to embed in URLs and JSON
+    #[wasm_bindgen]
+    pub fn to_base64url(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.encode())
+    }
+
+    /// Parse a base64url string produced by `to_base64url`
+    #[wasm_bindgen]
+    pub fn from_base64url(text: &str) -> Result<VollyEnvelope, JsValue> {
+        let bytes = URL_SAFE_NO_PAD.decode(text)
+            .map_err(|e| JsValue::from_str(&format!("Invalid base64url envelope: {:?}", e)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// A plain object `{ kind, suiteId, version, payload }` suitable for `JSON.stringify`,
+    /// with the payload itself base64url-encoded
+    #[wasm_bindgen]
+    pub fn to_object(&self) -> js_sys::Object {
+        let obj = js_sys::Object::new();
+        js_sys::Reflect::set(&obj, &"kind".into(), &self.kind.name().into()).unwrap();
+        js_sys::Reflect::set(&obj, &"suiteId".into(), &(self.suite_id as u32).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"version".into(), &(self.version as u32).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"payload".into(), &URL_SAFE_NO_PAD.encode(&self.payload[..]).into()).unwrap();
+        obj
+    }
+}
+
+/// Strip and validate a leading suite-id byte, returning the suite and the remaining payload.
+fn split_kem_suite(bytes: &[u8]) -> Result<(KemSuite, &[u8]), JsValue> {
+    let (&id, rest) = bytes
+        .split_first()
+        .ok_or_else(|| JsValue::from_str("Buffer is empty; expected a suite-id byte"))?;
+    Ok((KemSuite::from_id(id)?, rest))
+}
+
+/// Strip and validate a leading suite-id byte, returning the suite and the remaining payload.
+fn split_dsa_suite(bytes: &[u8]) -> Result<(DsaSuite, &[u8]), JsValue> {
+    let (&id, rest) = bytes
+        .split_first()
+        .ok_or_else(|| JsValue::from_str("Buffer is empty; expected a suite-id byte"))?;
+    Ok((DsaSuite::from_id(id)?, rest))
+}
+
+/// Prefix a suite-id byte onto a payload for export.
+fn with_suite_prefix(id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 1);
+    out.push(id);
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Like `with_suite_prefix`, but for secret-key material: the suite-prefixed copy is wiped
+/// on drop instead of lingering in plain heap memory for the rest of the call.
+fn with_suite_prefix_zeroizing(id: u8, payload: &[u8]) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(with_suite_prefix(id, payload))
+}
+
+/// FIPS 204 encodes the context length in a single byte, so it can never exceed this.
+const MAX_CONTEXT_LEN: usize = 255;
+
+fn validate_context(context: &[u8]) -> Result<(), JsValue> {
+    if context.len() > MAX_CONTEXT_LEN {
+        return Err(JsValue::from_str(&format!(
+            "Context must be at most {} bytes, got {}",
+            MAX_CONTEXT_LEN,
+            context.len()
+        )));
+    }
+    Ok(())
+}
+
+/// DER AlgorithmIdentifier OID for SHA3-256 (FIPS 204 Table 1), used to tag a
+/// caller-supplied digest so the pre-hash path can tell which hash function produced it.
+const SHA3_256_OID: [u8; 11] = [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08];
+
+/// Build the pre-hash message representative: the OID of the hash function prepended to the
+/// caller-supplied digest. This is our own construction for pre-hash signing, not a certified
+/// implementation of FIPS 204's HashML-DSA — that requires the underlying signer to flip an
+/// internal hash-mode bit, which the `fips204` crate doesn't expose. Domain separation from
+/// pure-message signing is instead handled via `prehash_context` below.
+fn oid_tagged_digest(digest: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(SHA3_256_OID.len() + digest.len());
+    out.extend_from_slice(&SHA3_256_OID);
+    out.extend_from_slice(digest);
+    out
+}
+
+/// Byte appended to the caller's context for pre-hash signing. Pure-mode signing always uses
+/// the caller's context exactly as given, so a pre-hash signature's effective context can only
+/// collide with a pure-mode signature's if the caller deliberately reproduces this suffix —
+/// routine use of `sign`/`sign_with_context` over the same digest bytes can't produce it.
+const PREHASH_CONTEXT_SUFFIX: u8 = 0x01;
+
+/// Append the pre-hash domain-separation suffix to a caller-supplied context.
+fn prehash_context(context: &[u8]) -> Result<Vec<u8>, JsValue> {
+    if context.len() >= MAX_CONTEXT_LEN {
+        return Err(JsValue::from_str(&format!(
+            "Context must leave room for the pre-hash suffix: at most {} bytes, got {}",
+            MAX_CONTEXT_LEN - 1,
+            context.len()
+        )));
+    }
+    let mut out = Vec::with_capacity(context.len() + 1);
+    out.extend_from_slice(context);
+    out.push(PREHASH_CONTEXT_SUFFIX);
+    Ok(out)
+}
+
+/// Sign with an already-seeded RNG, shared by the randomized and deterministic signing paths.
+/// `secret_key` must already have its suite-id prefix stripped.
+fn sign_with_suite_rng(
+    suite: DsaSuite,
+    secret_key: &[u8],
+    message: &[u8],
+    context: &[u8],
+    rng: &mut ChaCha20Rng,
+) -> Result<Vec<u8>, JsValue> {
+    if secret_key.len() != suite.sk_len() {
+        return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", suite.sk_len(), secret_key.len())));
+    }
+
+    let signature = match suite {
+        DsaSuite::MlDsa44 => {
+            let mut sk_array = [0u8; ml_dsa_44::SK_LEN];
+            sk_array.copy_from_slice(secret_key);
+            let sk = <ml_dsa_44::PrivateKey as DsaSerDes>::try_from_bytes(sk_array)
+                .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+            sk_array.zeroize();
+            sk.try_sign_with_rng(rng, message, context)
+                .map_err(|e| JsValue::from_str(&format!("Signing failed: {:?}", e)))?
+                .to_vec()
+        }
+        DsaSuite::MlDsa65 => {
+            let mut sk_array = [0u8; ml_dsa_65::SK_LEN];
+            sk_array.copy_from_slice(secret_key);
+            let sk = <ml_dsa_65::PrivateKey as DsaSerDes>::try_from_bytes(sk_array)
+                .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+            sk_array.zeroize();
+            sk.try_sign_with_rng(rng, message, context)
+                .map_err(|e| JsValue::from_str(&format!("Signing failed: {:?}", e)))?
+                .to_vec()
+        }
+        DsaSuite::MlDsa87 => {
+            let mut sk_array = [0u8; ml_dsa_87::SK_LEN];
+            sk_array.copy_from_slice(secret_key);
+            let sk = <ml_dsa_87::PrivateKey as DsaSerDes>::try_from_bytes(sk_array)
+                .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+            sk_array.zeroize();
+            sk.try_sign_with_rng(rng, message, context)
+                .map_err(|e| JsValue::from_str(&format!("Signing failed: {:?}", e)))?
+                .to_vec()
+        }
+    };
+
+    Ok(signature)
+}
+
+// Volly KEM (Key Encapsulation Mechanism) - versioned ML-KEM suite
 #[wasm_bindgen]
 pub struct VollyKEM {
+    suite: KemSuite,
     public_key: Vec<u8>,
-    secret_key: Vec<u8>,
+    secret_key: Zeroizing<Vec<u8>>,
 }
 
 #[wasm_bindgen]
 impl VollyKEM {
-    /// Create a new VollyKEM instance with fresh keypair
+    /// Create a new VollyKEM instance with fresh keypair (defaults to ML-KEM-768)
     #[wasm_bindgen(constructor)]
     pub fn new() -> Result<VollyKEM, JsValue> {
+        Self::with_suite("ML-KEM-768")
+    }
+
+    /// Create a new VollyKEM instance with a fresh keypair for the named suite
+    /// (one of "ML-KEM-512", "ML-KEM-768", "ML-KEM-1024")
+    #[wasm_bindgen]
+    pub fn with_suite(suite: &str) -> Result<VollyKEM, JsValue> {
+        let suite = KemSuite::from_name(suite)?;
         let start_time = js_sys::Date::now();
-        
+
         // Generate random seed
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
             .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
-        
+
         let mut rng = ChaCha20Rng::from_seed(seed);
-        
-        // Generate ML-KEM-768 keypair using correct API
-        let (ek, dk) = ml_kem_768::KG::try_keygen_with_rng(&mut rng)
-            .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
-        
-        let public_key = ek.into_bytes().to_vec();
-        let secret_key = dk.into_bytes().to_vec();
-        
+
+        let (public_key, secret_key) = match suite {
+            KemSuite::MlKem512 => {
+                let (ek, dk) = ml_kem_512::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+            KemSuite::MlKem768 => {
+                let (ek, dk) = ml_kem_768::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+            KemSuite::MlKem1024 => {
+                let (ek, dk) = ml_kem_1024::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+        };
+
         let end_time = js_sys::Date::now();
-        
+
         // Log performance metrics
-        console::log_1(&format!("Key generation took: {:.2}ms", end_time - start_time).into());
-        
+        console::log_1(&format!("{} key generation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(VollyKEM {
+            suite,
             public_key,
-            secret_key,
+            secret_key: Zeroizing::new(secret_key),
         })
     }
-    
-    /// Generate keypair from seed (deterministic)
+
+    /// Generate keypair from seed (deterministic), defaulting to ML-KEM-768
     #[wasm_bindgen]
     pub fn from_seed(seed: &[u8]) -> Result<VollyKEM, JsValue> {
+        Self::from_seed_with_suite(seed, "ML-KEM-768")
+    }
+
+    /// Generate keypair from seed (deterministic) for the named suite
+    #[wasm_bindgen]
+    pub fn from_seed_with_suite(seed: &[u8], suite: &str) -> Result<VollyKEM, JsValue> {
         if seed.len() != 32 {
             return Err(JsValue::from_str("Seed must be exactly 32 bytes"));
         }
-        
+        let suite = KemSuite::from_name(suite)?;
+
         let start_time = js_sys::Date::now();
-        
+
         // Create deterministic seed from input
         let mut hasher = Sha3_256::new();
-        hasher.update(seed);
-        let hash = hasher.finalize();
-        let seed_array: [u8; 32] = hash.into();
-        
-        let mut rng = ChaCha20Rng::from_seed(seed_array);
-        
-        // Generate ML-KEM-768 keypair deterministically
+        hasher.update(seed);
+        let hash = hasher.finalize();
+        let seed_array: [u8; 32] = hash.into();
+
+        let mut rng = ChaCha20Rng::from_seed(seed_array);
+
+        let (public_key, secret_key) = match suite {
+            KemSuite::MlKem512 => {
+                let (ek, dk) = ml_kem_512::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+            KemSuite::MlKem768 => {
+                let (ek, dk) = ml_kem_768::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+            KemSuite::MlKem1024 => {
+                let (ek, dk) = ml_kem_1024::KG::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (ek.into_bytes().to_vec(), dk.into_bytes().to_vec())
+            }
+        };
+
+        let end_time = js_sys::Date::now();
+
+        console::log_1(&format!("Deterministic {} key generation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
+        Ok(VollyKEM {
+            suite,
+            public_key,
+            secret_key: Zeroizing::new(secret_key),
+        })
+    }
+
+    /// Get the negotiated suite name, e.g. "ML-KEM-768"
+    #[wasm_bindgen(getter)]
+    pub fn suite(&self) -> String {
+        self.suite.name().to_string()
+    }
+
+    /// Get the public key, prefixed with a suite-id byte
+    #[wasm_bindgen(getter)]
+    pub fn public_key(&self) -> Uint8Array {
+        Uint8Array::from(&with_suite_prefix(self.suite.id(), &self.public_key)[..])
+    }
+
+    /// Explicitly opt in to exporting the secret key (suite-prefixed) as a JS-managed array.
+    /// Not a property getter on purpose, so callers can't pull key bytes into GC memory by accident.
+    #[wasm_bindgen]
+    pub fn export_secret_key(&self) -> Uint8Array {
+        Uint8Array::from(&with_suite_prefix(self.suite.id(), &self.secret_key)[..])
+    }
+
+    /// Scrub the secret key from memory immediately instead of waiting for drop
+    #[wasm_bindgen]
+    pub fn destroy(&mut self) {
+        self.secret_key.zeroize();
+    }
+
+    /// Get the public key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn public_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::KemPublicKey, self.suite.id(), self.public_key.clone())
+    }
+
+    /// Explicitly opt in to exporting the secret key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn export_secret_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::KemSecretKey, self.suite.id(), self.secret_key.to_vec())
+    }
+
+    /// Decapsulate using an enveloped (suite- and kind-checked) ciphertext
+    #[wasm_bindgen]
+    pub fn decapsulate_envelope(&self, ciphertext: &VollyEnvelope) -> Result<Uint8Array, JsValue> {
+        let ciphertext = ciphertext.expect(ArtifactKind::KemCiphertext, self.suite.id())?;
+        self.decapsulate(&with_suite_prefix(self.suite.id(), ciphertext))
+    }
+
+    /// Static method to decapsulate using an enveloped private key and an enveloped ciphertext
+    #[wasm_bindgen]
+    pub fn decapsulate_with_key_envelope(secret_key: &VollyEnvelope, ciphertext: &VollyEnvelope) -> Result<Uint8Array, JsValue> {
+        if secret_key.kind != ArtifactKind::KemSecretKey {
+            return Err(JsValue::from_str(&format!("Envelope type mismatch: expected KemSecretKey, got {}", secret_key.kind.name())));
+        }
+        if ciphertext.kind != ArtifactKind::KemCiphertext {
+            return Err(JsValue::from_str(&format!("Envelope type mismatch: expected KemCiphertext, got {}", ciphertext.kind.name())));
+        }
+        Self::decapsulate_with_key(
+            &with_suite_prefix_zeroizing(secret_key.suite_id, &secret_key.payload),
+            &with_suite_prefix(ciphertext.suite_id, &ciphertext.payload),
+        )
+    }
+
+    /// Encapsulate a shared secret against the given (suite-prefixed) public key
+    #[wasm_bindgen]
+    pub fn encapsulate(&self, public_key: &[u8]) -> Result<VollyEncapsulation, JsValue> {
+        let (suite, public_key) = split_kem_suite(public_key)?;
+        let start_time = js_sys::Date::now();
+
+        if public_key.len() != suite.ek_len() {
+            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", suite.ek_len(), public_key.len())));
+        }
+
+        // Generate random seed for encapsulation
+        let mut seed = [0u8; 32];
+        getrandom(&mut seed)
+            .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
+
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
+        let (ciphertext, shared_secret) = match suite {
+            KemSuite::MlKem512 => {
+                let mut pk_array = [0u8; ml_kem_512::EK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let ek = ml_kem_512::EncapsKey::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let (shared_secret, ciphertext) = ek.try_encaps_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Encapsulation failed: {:?}", e)))?;
+                (ciphertext.into_bytes().to_vec(), shared_secret.into_bytes().to_vec())
+            }
+            KemSuite::MlKem768 => {
+                let mut pk_array = [0u8; ml_kem_768::EK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let ek = ml_kem_768::EncapsKey::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let (shared_secret, ciphertext) = ek.try_encaps_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Encapsulation failed: {:?}", e)))?;
+                (ciphertext.into_bytes().to_vec(), shared_secret.into_bytes().to_vec())
+            }
+            KemSuite::MlKem1024 => {
+                let mut pk_array = [0u8; ml_kem_1024::EK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let ek = ml_kem_1024::EncapsKey::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let (shared_secret, ciphertext) = ek.try_encaps_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Encapsulation failed: {:?}", e)))?;
+                (ciphertext.into_bytes().to_vec(), shared_secret.into_bytes().to_vec())
+            }
+        };
+
+        let end_time = js_sys::Date::now();
+
+        console::log_1(&format!("{} encapsulation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
+        Ok(VollyEncapsulation {
+            ciphertext: with_suite_prefix(suite.id(), &ciphertext),
+            shared_secret,
+        })
+    }
+
+    /// Encapsulate against an enveloped (kind-checked) public key
+    #[wasm_bindgen]
+    pub fn encapsulate_envelope(&self, public_key: &VollyEnvelope) -> Result<VollyEncapsulation, JsValue> {
+        if public_key.kind != ArtifactKind::KemPublicKey {
+            return Err(JsValue::from_str(&format!("Envelope type mismatch: expected KemPublicKey, got {}", public_key.kind.name())));
+        }
+        self.encapsulate(&with_suite_prefix(public_key.suite_id, &public_key.payload))
+    }
+
+    /// Decapsulate a shared secret from the given (suite-prefixed) ciphertext using this instance's private key
+    #[wasm_bindgen]
+    pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<Uint8Array, JsValue> {
+        Self::decapsulate_with_key(&with_suite_prefix_zeroizing(self.suite.id(), &self.secret_key), ciphertext)
+    }
+
+    /// Static method to decapsulate using any (suite-prefixed) private key
+    #[wasm_bindgen]
+    pub fn decapsulate_with_key(secret_key: &[u8], ciphertext: &[u8]) -> Result<Uint8Array, JsValue> {
+        let (sk_suite, secret_key) = split_kem_suite(secret_key)?;
+        let (ct_suite, ciphertext) = split_kem_suite(ciphertext)?;
+        if sk_suite != ct_suite {
+            return Err(JsValue::from_str(&format!(
+                "Suite mismatch: secret key is {} but ciphertext is {}",
+                sk_suite.name(),
+                ct_suite.name()
+            )));
+        }
+        let suite = sk_suite;
+        let start_time = js_sys::Date::now();
+
+        if ciphertext.len() != suite.ct_len() {
+            return Err(JsValue::from_str(&format!("Invalid ciphertext length: expected {}, got {}", suite.ct_len(), ciphertext.len())));
+        }
+        if secret_key.len() != suite.dk_len() {
+            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", suite.dk_len(), secret_key.len())));
+        }
+
+        let shared_secret = match suite {
+            KemSuite::MlKem512 => {
+                let mut ct_array = [0u8; ml_kem_512::CT_LEN];
+                ct_array.copy_from_slice(ciphertext);
+                let ct = ml_kem_512::CipherText::try_from_bytes(ct_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid ciphertext: {:?}", e)))?;
+                let mut sk_array = [0u8; ml_kem_512::DK_LEN];
+                sk_array.copy_from_slice(secret_key);
+                let dk = ml_kem_512::DecapsKey::try_from_bytes(sk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+                sk_array.zeroize();
+                dk.try_decaps(&ct)
+                    .map_err(|e| JsValue::from_str(&format!("Decapsulation failed: {:?}", e)))?
+                    .into_bytes()
+                    .to_vec()
+            }
+            KemSuite::MlKem768 => {
+                let mut ct_array = [0u8; ml_kem_768::CT_LEN];
+                ct_array.copy_from_slice(ciphertext);
+                let ct = ml_kem_768::CipherText::try_from_bytes(ct_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid ciphertext: {:?}", e)))?;
+                let mut sk_array = [0u8; ml_kem_768::DK_LEN];
+                sk_array.copy_from_slice(secret_key);
+                let dk = ml_kem_768::DecapsKey::try_from_bytes(sk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+                sk_array.zeroize();
+                dk.try_decaps(&ct)
+                    .map_err(|e| JsValue::from_str(&format!("Decapsulation failed: {:?}", e)))?
+                    .into_bytes()
+                    .to_vec()
+            }
+            KemSuite::MlKem1024 => {
+                let mut ct_array = [0u8; ml_kem_1024::CT_LEN];
+                ct_array.copy_from_slice(ciphertext);
+                let ct = ml_kem_1024::CipherText::try_from_bytes(ct_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid ciphertext: {:?}", e)))?;
+                let mut sk_array = [0u8; ml_kem_1024::DK_LEN];
+                sk_array.copy_from_slice(secret_key);
+                let dk = ml_kem_1024::DecapsKey::try_from_bytes(sk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
+                sk_array.zeroize();
+                dk.try_decaps(&ct)
+                    .map_err(|e| JsValue::from_str(&format!("Decapsulation failed: {:?}", e)))?
+                    .into_bytes()
+                    .to_vec()
+            }
+        };
+
+        let end_time = js_sys::Date::now();
+
+        console::log_1(&format!("{} static decapsulation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
+        Ok(Uint8Array::from(&shared_secret[..]))
+    }
+
+    /// Create a VollyKEM instance from existing (suite-prefixed) keys
+    #[wasm_bindgen]
+    pub fn from_keys(public_key: &[u8], secret_key: &[u8]) -> Result<VollyKEM, JsValue> {
+        let (pk_suite, public_key) = split_kem_suite(public_key)?;
+        let (sk_suite, secret_key) = split_kem_suite(secret_key)?;
+        if pk_suite != sk_suite {
+            return Err(JsValue::from_str(&format!(
+                "Suite mismatch: public key is {} but secret key is {}",
+                pk_suite.name(),
+                sk_suite.name()
+            )));
+        }
+        let suite = pk_suite;
+
+        if public_key.len() != suite.ek_len() {
+            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", suite.ek_len(), public_key.len())));
+        }
+        if secret_key.len() != suite.dk_len() {
+            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", suite.dk_len(), secret_key.len())));
+        }
+
+        Ok(VollyKEM {
+            suite,
+            public_key: public_key.to_vec(),
+            secret_key: Zeroizing::new(secret_key.to_vec()),
+        })
+    }
+
+    /// Get key sizes for validation, defaulting to ML-KEM-768
+    #[wasm_bindgen]
+    pub fn key_sizes() -> js_sys::Object {
+        Self::key_sizes_for_suite("ML-KEM-768").unwrap()
+    }
+
+    /// Get key sizes for validation for the named suite
+    #[wasm_bindgen]
+    pub fn key_sizes_for_suite(suite: &str) -> Result<js_sys::Object, JsValue> {
+        let suite = KemSuite::from_name(suite)?;
+        let obj = js_sys::Object::new();
+        js_sys::Reflect::set(&obj, &"suite".into(), &suite.name().into()).unwrap();
+        js_sys::Reflect::set(&obj, &"publicKey".into(), &(suite.ek_len() as u32 + 1).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"secretKey".into(), &(suite.dk_len() as u32 + 1).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"ciphertext".into(), &(suite.ct_len() as u32 + 1).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"sharedSecret".into(), &32u32.into()).unwrap(); // ML-KEM shared secret is always 32 bytes
+        Ok(obj)
+    }
+}
+
+/// Encapsulation result containing ciphertext and shared secret
+#[wasm_bindgen]
+pub struct VollyEncapsulation {
+    ciphertext: Vec<u8>,
+    shared_secret: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl VollyEncapsulation {
+    /// Get the (suite-prefixed) ciphertext
+    #[wasm_bindgen(getter)]
+    pub fn ciphertext(&self) -> Uint8Array {
+        Uint8Array::from(&self.ciphertext[..])
+    }
+
+    /// Get the shared secret
+    #[wasm_bindgen(getter)]
+    pub fn shared_secret(&self) -> Uint8Array {
+        Uint8Array::from(&self.shared_secret[..])
+    }
+
+    /// Get the ciphertext wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn ciphertext_envelope(&self) -> Result<VollyEnvelope, JsValue> {
+        let (suite, ciphertext) = split_kem_suite(&self.ciphertext)?;
+        Ok(VollyEnvelope::wrap(ArtifactKind::KemCiphertext, suite.id(), ciphertext.to_vec()))
+    }
+}
+
+// Volly Hybrid KEM: ML-KEM-768 combined with X25519 so the session key stays
+// secure as long as either the post-quantum or the classical primitive holds.
+#[wasm_bindgen]
+pub struct VollyHybridKEM {
+    mlkem_public: Vec<u8>,
+    mlkem_secret: Zeroizing<Vec<u8>>,
+    x25519_public: [u8; 32],
+    x25519_secret: Zeroizing<[u8; 32]>,
+}
+
+impl VollyHybridKEM {
+    /// Combine the ML-KEM and X25519 shared secrets into a single 32-byte session key.
+    /// Binding the ciphertext and ephemeral public key into the KDF input ties the
+    /// derived key to this exact transcript rather than just the raw secrets.
+    fn combine(ss_mlkem: &[u8], ss_x25519: &[u8], ct_mlkem: &[u8], eph_pub: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha3_256::new();
+        hasher.update(ss_mlkem);
+        hasher.update(ss_x25519);
+        hasher.update(ct_mlkem);
+        hasher.update(eph_pub);
+        hasher.finalize().to_vec()
+    }
+}
+
+#[wasm_bindgen]
+impl VollyHybridKEM {
+    /// Create a new VollyHybridKEM instance with fresh ML-KEM-768 and X25519 keypairs
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<VollyHybridKEM, JsValue> {
+        let start_time = js_sys::Date::now();
+
+        let mut seed = [0u8; 32];
+        getrandom(&mut seed)
+            .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
         let (ek, dk) = ml_kem_768::KG::try_keygen_with_rng(&mut rng)
             .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
-        
-        let public_key = ek.into_bytes().to_vec();
-        let secret_key = dk.into_bytes().to_vec();
-        
+
+        let x25519_secret = StaticSecret::random_from_rng(&mut rng);
+        let x25519_public = X25519PublicKey::from(&x25519_secret);
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Deterministic key generation took: {:.2}ms", end_time - start_time).into());
-        
-        Ok(VollyKEM {
-            public_key,
-            secret_key,
+
+        console::log_1(&format!("Hybrid key generation took: {:.2}ms", end_time - start_time).into());
+
+        Ok(VollyHybridKEM {
+            mlkem_public: ek.into_bytes().to_vec(),
+            mlkem_secret: Zeroizing::new(dk.into_bytes().to_vec()),
+            x25519_public: x25519_public.to_bytes(),
+            x25519_secret: Zeroizing::new(x25519_secret.to_bytes()),
         })
     }
-    
-    /// Get the public key
+
+    /// Get the combined public key: ML-KEM-768 encapsulation key followed by the X25519 public key
     #[wasm_bindgen(getter)]
     pub fn public_key(&self) -> Uint8Array {
-        Uint8Array::from(&self.public_key[..])
+        let mut out = Vec::with_capacity(self.mlkem_public.len() + 32);
+        out.extend_from_slice(&self.mlkem_public);
+        out.extend_from_slice(&self.x25519_public);
+        Uint8Array::from(&out[..])
     }
-    
-    /// Get the secret key (use with caution!)
-    #[wasm_bindgen(getter)]
-    pub fn secret_key(&self) -> Uint8Array {
-        Uint8Array::from(&self.secret_key[..])
+
+    /// Explicitly opt in to exporting the combined secret key as a JS-managed array.
+    /// Not a property getter on purpose, so callers can't pull key bytes into GC memory by accident.
+    #[wasm_bindgen]
+    pub fn export_secret_key(&self) -> Uint8Array {
+        let mut out = Vec::with_capacity(self.mlkem_secret.len() + 32);
+        out.extend_from_slice(&self.mlkem_secret);
+        out.extend_from_slice(&*self.x25519_secret);
+        Uint8Array::from(&out[..])
     }
-    
-    /// Encapsulate a shared secret against the given public key
+
+    /// Scrub both secret-key halves from memory immediately instead of waiting for drop
     #[wasm_bindgen]
-    pub fn encapsulate(&self, public_key: &[u8]) -> Result<VollyEncapsulation, JsValue> {
-        let start_time = js_sys::Date::now();
-        
-        // Parse the public key - convert slice to fixed-size array
-        if public_key.len() != ml_kem_768::EK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", ml_kem_768::EK_LEN, public_key.len())));
+    pub fn destroy(&mut self) {
+        self.mlkem_secret.zeroize();
+        self.x25519_secret.zeroize();
+    }
+
+    /// Get the combined public key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn public_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::HybridKemPublicKey, HYBRID_SUITE_ID, self.public_key().to_vec())
+    }
+
+    /// Explicitly opt in to exporting the combined secret key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn export_secret_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::HybridKemSecretKey, HYBRID_SUITE_ID, self.export_secret_key().to_vec())
+    }
+
+    /// Decapsulate using an enveloped (kind-checked) combined ciphertext
+    #[wasm_bindgen]
+    pub fn decapsulate_envelope(&self, ciphertext: &VollyEnvelope) -> Result<Uint8Array, JsValue> {
+        let ciphertext = ciphertext.expect(ArtifactKind::HybridKemCiphertext, HYBRID_SUITE_ID)?;
+        self.decapsulate(ciphertext)
+    }
+
+    /// Encapsulate a hybrid shared secret against the given combined public key
+    #[wasm_bindgen]
+    pub fn encapsulate(&self, public_key: &[u8]) -> Result<VollyHybridEncapsulation, JsValue> {
+        if public_key.len() != ml_kem_768::EK_LEN + 32 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid public key length: expected {}, got {}",
+                ml_kem_768::EK_LEN + 32,
+                public_key.len()
+            )));
         }
+        let (mlkem_pub, x25519_pub) = public_key.split_at(ml_kem_768::EK_LEN);
+
+        let start_time = js_sys::Date::now();
+
         let mut pk_array = [0u8; ml_kem_768::EK_LEN];
-        pk_array.copy_from_slice(public_key);
+        pk_array.copy_from_slice(mlkem_pub);
         let ek = ml_kem_768::EncapsKey::try_from_bytes(pk_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
-        
-        // Generate random seed for encapsulation
+            .map_err(|e| JsValue::from_str(&format!("Invalid ML-KEM public key: {:?}", e)))?;
+
+        let mut peer_x25519 = [0u8; 32];
+        peer_x25519.copy_from_slice(x25519_pub);
+        let peer_x25519 = X25519PublicKey::from(peer_x25519);
+
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
             .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
-        
         let mut rng = ChaCha20Rng::from_seed(seed);
-        
-        let (shared_secret, ciphertext) = ek.try_encaps_with_rng(&mut rng)
+
+        let (ss_mlkem, ct_mlkem) = ek.try_encaps_with_rng(&mut rng)
             .map_err(|e| JsValue::from_str(&format!("Encapsulation failed: {:?}", e)))?;
-        
+        let ct_mlkem = ct_mlkem.into_bytes().to_vec();
+
+        let eph_secret = EphemeralSecret::random_from_rng(&mut rng);
+        let eph_public = X25519PublicKey::from(&eph_secret);
+        let ss_x25519 = eph_secret.diffie_hellman(&peer_x25519);
+
+        let shared_secret = Self::combine(
+            &ss_mlkem.into_bytes(),
+            ss_x25519.as_bytes(),
+            &ct_mlkem,
+            eph_public.as_bytes(),
+        );
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Encapsulation took: {:.2}ms", end_time - start_time).into());
-        
-        Ok(VollyEncapsulation {
-            ciphertext: ciphertext.into_bytes().to_vec(),
-            shared_secret: shared_secret.into_bytes().to_vec(),
+
+        console::log_1(&format!("Hybrid encapsulation took: {:.2}ms", end_time - start_time).into());
+
+        let mut ciphertext = Vec::with_capacity(ct_mlkem.len() + 32);
+        ciphertext.extend_from_slice(&ct_mlkem);
+        ciphertext.extend_from_slice(eph_public.as_bytes());
+
+        Ok(VollyHybridEncapsulation {
+            ciphertext,
+            shared_secret,
         })
     }
-    
-    /// Decapsulate a shared secret from the given ciphertext using this instance's private key
+
+    /// Decapsulate a hybrid shared secret from the given combined ciphertext
     #[wasm_bindgen]
     pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<Uint8Array, JsValue> {
-        let start_time = js_sys::Date::now();
-        
-        // Parse the ciphertext - convert slice to fixed-size array
-        if ciphertext.len() != ml_kem_768::CT_LEN {
-            return Err(JsValue::from_str(&format!("Invalid ciphertext length: expected {}, got {}", ml_kem_768::CT_LEN, ciphertext.len())));
-        }
-        let mut ct_array = [0u8; ml_kem_768::CT_LEN];
-        ct_array.copy_from_slice(ciphertext);
-        let ct = ml_kem_768::CipherText::try_from_bytes(ct_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid ciphertext: {:?}", e)))?;
-        
-        // Parse the secret key - convert Vec to fixed-size array
-        if self.secret_key.len() != ml_kem_768::DK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", ml_kem_768::DK_LEN, self.secret_key.len())));
+        if ciphertext.len() != ml_kem_768::CT_LEN + 32 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid ciphertext length: expected {}, got {}",
+                ml_kem_768::CT_LEN + 32,
+                ciphertext.len()
+            )));
         }
-        let mut sk_array = [0u8; ml_kem_768::DK_LEN];
-        sk_array.copy_from_slice(&self.secret_key);
-        let dk = ml_kem_768::DecapsKey::try_from_bytes(sk_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
-        
-        let shared_secret = dk.try_decaps(&ct)
-            .map_err(|e| JsValue::from_str(&format!("Decapsulation failed: {:?}", e)))?;
-        
-        let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Decapsulation took: {:.2}ms", end_time - start_time).into());
-        
-        Ok(Uint8Array::from(&shared_secret.into_bytes()[..]))
-    }
-    
-    /// Static method to decapsulate using any private key
-    #[wasm_bindgen]
-    pub fn decapsulate_with_key(secret_key: &[u8], ciphertext: &[u8]) -> Result<Uint8Array, JsValue> {
+        let (ct_mlkem, eph_pub) = ciphertext.split_at(ml_kem_768::CT_LEN);
+
         let start_time = js_sys::Date::now();
-        
-        // Parse the ciphertext - convert slice to fixed-size array
-        if ciphertext.len() != ml_kem_768::CT_LEN {
-            return Err(JsValue::from_str(&format!("Invalid ciphertext length: expected {}, got {}", ml_kem_768::CT_LEN, ciphertext.len())));
-        }
+
         let mut ct_array = [0u8; ml_kem_768::CT_LEN];
-        ct_array.copy_from_slice(ciphertext);
+        ct_array.copy_from_slice(ct_mlkem);
         let ct = ml_kem_768::CipherText::try_from_bytes(ct_array)
             .map_err(|e| JsValue::from_str(&format!("Invalid ciphertext: {:?}", e)))?;
-        
-        // Parse the secret key - convert slice to fixed-size array
-        if secret_key.len() != ml_kem_768::DK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", ml_kem_768::DK_LEN, secret_key.len())));
-        }
+
         let mut sk_array = [0u8; ml_kem_768::DK_LEN];
-        sk_array.copy_from_slice(secret_key);
+        sk_array.copy_from_slice(&self.mlkem_secret);
         let dk = ml_kem_768::DecapsKey::try_from_bytes(sk_array)
             .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
-        
-        let shared_secret = dk.try_decaps(&ct)
+        sk_array.zeroize();
+
+        let ss_mlkem = dk.try_decaps(&ct)
             .map_err(|e| JsValue::from_str(&format!("Decapsulation failed: {:?}", e)))?;
-        
+
+        let eph_public = X25519PublicKey::from({
+            let mut buf = [0u8; 32];
+            buf.copy_from_slice(eph_pub);
+            buf
+        });
+        let ss_x25519 = StaticSecret::from(*self.x25519_secret).diffie_hellman(&eph_public);
+
+        let shared_secret = Self::combine(
+            &ss_mlkem.into_bytes(),
+            ss_x25519.as_bytes(),
+            ct_mlkem,
+            eph_pub,
+        );
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Static decapsulation took: {:.2}ms", end_time - start_time).into());
-        
-        Ok(Uint8Array::from(&shared_secret.into_bytes()[..]))
-    }
-    
-    /// Create a VollyKEM instance from existing keys
-    #[wasm_bindgen]
-    pub fn from_keys(public_key: &[u8], secret_key: &[u8]) -> Result<VollyKEM, JsValue> {
-        // Validate key sizes
-        if public_key.len() != ml_kem_768::EK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", ml_kem_768::EK_LEN, public_key.len())));
-        }
-        
-        if secret_key.len() != ml_kem_768::DK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", ml_kem_768::DK_LEN, secret_key.len())));
-        }
-        
-        Ok(VollyKEM {
-            public_key: public_key.to_vec(),
-            secret_key: secret_key.to_vec(),
-        })
+
+        console::log_1(&format!("Hybrid decapsulation took: {:.2}ms", end_time - start_time).into());
+
+        Ok(Uint8Array::from(&shared_secret[..]))
     }
 
     /// Get key sizes for validation
     #[wasm_bindgen]
     pub fn key_sizes() -> js_sys::Object {
         let obj = js_sys::Object::new();
-        js_sys::Reflect::set(&obj, &"publicKey".into(), &(ml_kem_768::EK_LEN as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"secretKey".into(), &(ml_kem_768::DK_LEN as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"ciphertext".into(), &(ml_kem_768::CT_LEN as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"sharedSecret".into(), &32u32.into()).unwrap(); // ML-KEM shared secret is always 32 bytes
+        js_sys::Reflect::set(&obj, &"publicKey".into(), &((ml_kem_768::EK_LEN + 32) as u32).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"secretKey".into(), &((ml_kem_768::DK_LEN + 32) as u32).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"ciphertext".into(), &((ml_kem_768::CT_LEN + 32) as u32).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"sharedSecret".into(), &32u32.into()).unwrap();
         obj
     }
 }
 
-/// Encapsulation result containing ciphertext and shared secret
+/// Hybrid encapsulation result containing the combined ciphertext and derived shared secret
 #[wasm_bindgen]
-pub struct VollyEncapsulation {
+pub struct VollyHybridEncapsulation {
     ciphertext: Vec<u8>,
     shared_secret: Vec<u8>,
 }
 
 #[wasm_bindgen]
-impl VollyEncapsulation {
-    /// Get the ciphertext
+impl VollyHybridEncapsulation {
+    /// Get the combined ciphertext: ML-KEM-768 ciphertext followed by the ephemeral X25519 public key
     #[wasm_bindgen(getter)]
     pub fn ciphertext(&self) -> Uint8Array {
         Uint8Array::from(&self.ciphertext[..])
     }
-    
-    /// Get the shared secret
+
+    /// Get the derived 32-byte shared secret
     #[wasm_bindgen(getter)]
     pub fn shared_secret(&self) -> Uint8Array {
         Uint8Array::from(&self.shared_secret[..])
     }
+
+    /// Get the combined ciphertext wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn ciphertext_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::HybridKemCiphertext, HYBRID_SUITE_ID, self.ciphertext.clone())
+    }
 }
 
 // Utility functions
@@ -269,22 +1180,28 @@ pub fn get_version() -> String {
 
 #[wasm_bindgen]
 pub fn get_algorithm_info() -> js_sys::Object {
+    get_algorithm_info_for_suite("ML-KEM-768").unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_algorithm_info_for_suite(suite: &str) -> Result<js_sys::Object, JsValue> {
+    let suite = KemSuite::from_name(suite)?;
     let obj = js_sys::Object::new();
-    js_sys::Reflect::set(&obj, &"algorithm".into(), &"ML-KEM-768".into()).unwrap();
+    js_sys::Reflect::set(&obj, &"algorithm".into(), &suite.name().into()).unwrap();
     js_sys::Reflect::set(&obj, &"standard".into(), &"FIPS 203".into()).unwrap();
-    js_sys::Reflect::set(&obj, &"securityLevel".into(), &"Level 3 (192-bit post-quantum)".into()).unwrap();
-    js_sys::Reflect::set(&obj, &"publicKeySize".into(), &(ml_kem_768::EK_LEN as u32).into()).unwrap();
-    js_sys::Reflect::set(&obj, &"secretKeySize".into(), &(ml_kem_768::DK_LEN as u32).into()).unwrap();
-    js_sys::Reflect::set(&obj, &"ciphertextSize".into(), &(ml_kem_768::CT_LEN as u32).into()).unwrap();
+    js_sys::Reflect::set(&obj, &"securityLevel".into(), &suite.security_level().into()).unwrap();
+    js_sys::Reflect::set(&obj, &"publicKeySize".into(), &(suite.ek_len() as u32 + 1).into()).unwrap();
+    js_sys::Reflect::set(&obj, &"secretKeySize".into(), &(suite.dk_len() as u32 + 1).into()).unwrap();
+    js_sys::Reflect::set(&obj, &"ciphertextSize".into(), &(suite.ct_len() as u32 + 1).into()).unwrap();
     js_sys::Reflect::set(&obj, &"sharedSecretSize".into(), &32u32.into()).unwrap(); // ML-KEM shared secret is always 32 bytes
-    obj
+    Ok(obj)
 }
 
 #[wasm_bindgen]
 pub fn benchmark_keygen(iterations: u32) -> Result<f64, JsValue> {
     // Use a simple timing approach that works in both browser and Node.js
     let start_time = js_sys::Date::now();
-    
+
     for _ in 0..iterations {
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
@@ -293,25 +1210,25 @@ pub fn benchmark_keygen(iterations: u32) -> Result<f64, JsValue> {
         let _ = ml_kem_768::KG::try_keygen_with_rng(&mut rng)
             .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
     }
-    
+
     let end_time = js_sys::Date::now();
-    
+
     Ok((end_time - start_time) / iterations as f64)
 }
 
 #[wasm_bindgen]
 pub fn benchmark_encap(iterations: u32, public_key: &[u8]) -> Result<f64, JsValue> {
-    // Parse the public key once - convert slice to fixed-size array
-    if public_key.len() != ml_kem_768::EK_LEN {
+    let (suite, public_key) = split_kem_suite(public_key)?;
+    if suite != KemSuite::MlKem768 || public_key.len() != ml_kem_768::EK_LEN {
         return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", ml_kem_768::EK_LEN, public_key.len())));
     }
     let mut pk_array = [0u8; ml_kem_768::EK_LEN];
     pk_array.copy_from_slice(public_key);
     let ek = ml_kem_768::EncapsKey::try_from_bytes(pk_array)
         .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
-    
+
     let start_time = js_sys::Date::now();
-    
+
     for _ in 0..iterations {
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
@@ -320,213 +1237,527 @@ pub fn benchmark_encap(iterations: u32, public_key: &[u8]) -> Result<f64, JsValu
         let _ = ek.try_encaps_with_rng(&mut rng)
             .map_err(|e| JsValue::from_str(&format!("Encapsulation failed: {:?}", e)))?;
     }
-    
+
     let end_time = js_sys::Date::now();
-    
+
     Ok((end_time - start_time) / iterations as f64)
 }
 
-// Volly DSA (Digital Signature Algorithm) using ML-DSA-65
+// Volly DSA (Digital Signature Algorithm) - versioned ML-DSA suite
 #[wasm_bindgen]
 pub struct VollyDSA {
+    suite: DsaSuite,
     public_key: Vec<u8>,
-    secret_key: Vec<u8>,
+    secret_key: Zeroizing<Vec<u8>>,
 }
 
 #[wasm_bindgen]
 impl VollyDSA {
-    /// Create a new VollyDSA instance with fresh keypair
+    /// Create a new VollyDSA instance with fresh keypair (defaults to ML-DSA-65)
     #[wasm_bindgen(constructor)]
     pub fn new() -> Result<VollyDSA, JsValue> {
+        Self::with_suite("ML-DSA-65")
+    }
+
+    /// Create a new VollyDSA instance with a fresh keypair for the named suite
+    /// (one of "ML-DSA-44", "ML-DSA-65", "ML-DSA-87")
+    #[wasm_bindgen]
+    pub fn with_suite(suite: &str) -> Result<VollyDSA, JsValue> {
+        let suite = DsaSuite::from_name(suite)?;
         let start_time = js_sys::Date::now();
-        
+
         // Generate random seed
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
             .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
-        
+
         let mut rng = ChaCha20Rng::from_seed(seed);
-        
-        // Generate ML-DSA-65 keypair
-        let (pk, sk) = <ml_dsa_65::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
-            .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
-        
-        let public_key = pk.clone().into_bytes().to_vec();
-        let secret_key = sk.clone().into_bytes().to_vec();
-        
+
+        let (public_key, secret_key) = match suite {
+            DsaSuite::MlDsa44 => {
+                let (pk, sk) = <ml_dsa_44::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+            DsaSuite::MlDsa65 => {
+                let (pk, sk) = <ml_dsa_65::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+            DsaSuite::MlDsa87 => {
+                let (pk, sk) = <ml_dsa_87::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+        };
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("DSA key generation took: {:.2}ms", end_time - start_time).into());
-        
+
+        console::log_1(&format!("{} key generation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(VollyDSA {
+            suite,
             public_key,
-            secret_key,
+            secret_key: Zeroizing::new(secret_key),
         })
     }
-    
-    /// Generate keypair from seed (deterministic)
+
+    /// Generate keypair from seed (deterministic), defaulting to ML-DSA-65
     #[wasm_bindgen]
     pub fn from_seed(seed: &[u8]) -> Result<VollyDSA, JsValue> {
+        Self::from_seed_with_suite(seed, "ML-DSA-65")
+    }
+
+    /// Generate keypair from seed (deterministic) for the named suite
+    #[wasm_bindgen]
+    pub fn from_seed_with_suite(seed: &[u8], suite: &str) -> Result<VollyDSA, JsValue> {
         if seed.len() != 32 {
             return Err(JsValue::from_str("Seed must be exactly 32 bytes"));
         }
-        
+        let suite = DsaSuite::from_name(suite)?;
+
         let start_time = js_sys::Date::now();
-        
+
         // Create deterministic seed from input
         let mut hasher = Sha3_256::new();
         hasher.update(seed);
         let hash = hasher.finalize();
         let seed_array: [u8; 32] = hash.into();
-        
+
         let mut rng = ChaCha20Rng::from_seed(seed_array);
-        
-        // Generate ML-DSA-65 keypair deterministically
-        let (pk, sk) = <ml_dsa_65::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
-            .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
-        
-        let public_key = pk.clone().into_bytes().to_vec();
-        let secret_key = sk.clone().into_bytes().to_vec();
-        
+
+        let (public_key, secret_key) = match suite {
+            DsaSuite::MlDsa44 => {
+                let (pk, sk) = <ml_dsa_44::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+            DsaSuite::MlDsa65 => {
+                let (pk, sk) = <ml_dsa_65::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+            DsaSuite::MlDsa87 => {
+                let (pk, sk) = <ml_dsa_87::KG as DsaKeyGen>::try_keygen_with_rng(&mut rng)
+                    .map_err(|e| JsValue::from_str(&format!("Key generation failed: {:?}", e)))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }
+        };
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Deterministic DSA key generation took: {:.2}ms", end_time - start_time).into());
-        
+
+        console::log_1(&format!("Deterministic {} key generation took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(VollyDSA {
+            suite,
             public_key,
-            secret_key,
+            secret_key: Zeroizing::new(secret_key),
         })
     }
-    
-    /// Get the public key
+
+    /// Get the negotiated suite name, e.g. "ML-DSA-65"
     #[wasm_bindgen(getter)]
-    pub fn public_key(&self) -> Uint8Array {
-        Uint8Array::from(&self.public_key[..])
+    pub fn suite(&self) -> String {
+        self.suite.name().to_string()
     }
-    
-    /// Get the secret key (use with caution\!)
+
+    /// Get the public key, prefixed with a suite-id byte
     #[wasm_bindgen(getter)]
-    pub fn secret_key(&self) -> Uint8Array {
-        Uint8Array::from(&self.secret_key[..])
+    pub fn public_key(&self) -> Uint8Array {
+        Uint8Array::from(&with_suite_prefix(self.suite.id(), &self.public_key)[..])
+    }
+
+    /// Explicitly opt in to exporting the secret key (suite-prefixed) as a JS-managed array.
+    /// Not a property getter on purpose, so callers can't pull key bytes into GC memory by accident.
+    #[wasm_bindgen]
+    pub fn export_secret_key(&self) -> Uint8Array {
+        Uint8Array::from(&with_suite_prefix(self.suite.id(), &self.secret_key)[..])
+    }
+
+    /// Scrub the secret key from memory immediately instead of waiting for drop
+    #[wasm_bindgen]
+    pub fn destroy(&mut self) {
+        self.secret_key.zeroize();
+    }
+
+    /// Get the public key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn public_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::DsaPublicKey, self.suite.id(), self.public_key.clone())
+    }
+
+    /// Explicitly opt in to exporting the secret key wrapped in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn export_secret_key_envelope(&self) -> VollyEnvelope {
+        VollyEnvelope::wrap(ArtifactKind::DsaSecretKey, self.suite.id(), self.secret_key.to_vec())
     }
-    
-    /// Sign a message using this instance's private key
+
+    /// Sign a message using this instance's private key (empty context, for existing callers)
     #[wasm_bindgen]
     pub fn sign(&self, message: &[u8]) -> Result<Uint8Array, JsValue> {
-        let start_time = js_sys::Date::now();
-        
-        // Parse the secret key
-        if self.secret_key.len() != ml_dsa_65::SK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", ml_dsa_65::SK_LEN, self.secret_key.len())));
-        }
-        let mut sk_array = [0u8; ml_dsa_65::SK_LEN];
-        sk_array.copy_from_slice(&self.secret_key);
-        let sk = <ml_dsa_65::PrivateKey as DsaSerDes>::try_from_bytes(sk_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
-        
-        // Generate random seed for signing
+        self.sign_with_context(message, &[])
+    }
+
+    /// Sign a message using this instance's private key, returning the signature wrapped
+    /// in a self-describing envelope
+    #[wasm_bindgen]
+    pub fn sign_envelope(&self, message: &[u8], context: &[u8]) -> Result<VollyEnvelope, JsValue> {
+        let signature = self.sign_with_context(message, context)?;
+        Ok(VollyEnvelope::wrap(ArtifactKind::DsaSignature, self.suite.id(), signature.to_vec()))
+    }
+
+    /// Sign a message using this instance's private key, bound to the given domain-separation context
+    #[wasm_bindgen]
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> Result<Uint8Array, JsValue> {
+        Self::sign_with_key_and_context(&with_suite_prefix_zeroizing(self.suite.id(), &self.secret_key), message, context)
+    }
+
+    /// Static method to sign with any (suite-prefixed) private key (empty context, for existing callers)
+    #[wasm_bindgen]
+    pub fn sign_with_key(secret_key: &[u8], message: &[u8]) -> Result<Uint8Array, JsValue> {
+        Self::sign_with_key_and_context(secret_key, message, &[])
+    }
+
+    /// Static method to sign with any (suite-prefixed) private key, bound to the given domain-separation context
+    #[wasm_bindgen]
+    pub fn sign_with_key_and_context(secret_key: &[u8], message: &[u8], context: &[u8]) -> Result<Uint8Array, JsValue> {
+        validate_context(context)?;
+        let (suite, secret_key) = split_dsa_suite(secret_key)?;
+
         let mut seed = [0u8; 32];
         getrandom(&mut seed)
             .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
-        
         let mut rng = ChaCha20Rng::from_seed(seed);
-        
-        // Sign the message (fips204 requires a context parameter)
-        let context = b""; // Empty context for general signing
-        let signature = sk.try_sign_with_rng(&mut rng, message, context)
-            .map_err(|e| JsValue::from_str(&format!("Signing failed: {:?}", e)))?;
-        
+
+        let start_time = js_sys::Date::now();
+        let signature = sign_with_suite_rng(suite, secret_key, message, context, &mut rng)?;
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Signing took: {:.2}ms", end_time - start_time).into());
-        
+
+        console::log_1(&format!("{} static signing took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(Uint8Array::from(&signature[..]))
     }
-    
-    /// Static method to sign with any private key
+
+    /// Sign a message deterministically using this instance's private key (empty context, no RNG required)
     #[wasm_bindgen]
-    pub fn sign_with_key(secret_key: &[u8], message: &[u8]) -> Result<Uint8Array, JsValue> {
-        let start_time = js_sys::Date::now();
-        
-        // Parse the secret key
-        if secret_key.len() != ml_dsa_65::SK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid secret key length: expected {}, got {}", ml_dsa_65::SK_LEN, secret_key.len())));
-        }
-        let mut sk_array = [0u8; ml_dsa_65::SK_LEN];
-        sk_array.copy_from_slice(secret_key);
-        let sk = <ml_dsa_65::PrivateKey as DsaSerDes>::try_from_bytes(sk_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid secret key: {:?}", e)))?;
-        
-        // Generate random seed for signing
-        let mut seed = [0u8; 32];
-        getrandom(&mut seed)
-            .map_err(|e| JsValue::from_str(&format!("Random generation failed: {:?}", e)))?;
-        
+    pub fn sign_deterministic(&self, message: &[u8]) -> Result<Uint8Array, JsValue> {
+        Self::sign_deterministic_with_key(&with_suite_prefix_zeroizing(self.suite.id(), &self.secret_key), message, &[])
+    }
+
+    /// Static method to sign deterministically with any (suite-prefixed) private key and context.
+    ///
+    /// The RNG normally used to hedge ML-DSA signatures is replaced with
+    /// `ChaCha20Rng::from_seed(SHA3-256(secret_key || context || message))`, so identical inputs
+    /// always produce the identical signature. This differs from FIPS 204's own deterministic
+    /// mode, which fixes the signing randomizer to all zeros instead. This makes
+    /// signing reproducible for test vectors and conformance checks, and works even when no secure
+    /// RNG is available.
+    #[wasm_bindgen]
+    pub fn sign_deterministic_with_key(secret_key: &[u8], message: &[u8], context: &[u8]) -> Result<Uint8Array, JsValue> {
+        validate_context(context)?;
+        let (suite, secret_key) = split_dsa_suite(secret_key)?;
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(secret_key);
+        hasher.update(context);
+        hasher.update(message);
+        let seed: [u8; 32] = hasher.finalize().into();
         let mut rng = ChaCha20Rng::from_seed(seed);
-        
-        // Sign the message (fips204 requires a context parameter)
-        let context = b""; // Empty context for general signing
-        let signature = sk.try_sign_with_rng(&mut rng, message, context)
-            .map_err(|e| JsValue::from_str(&format!("Signing failed: {:?}", e)))?;
-        
+
+        let start_time = js_sys::Date::now();
+        let signature = sign_with_suite_rng(suite, secret_key, message, context, &mut rng)?;
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Static signing took: {:.2}ms", end_time - start_time).into());
-        
+
+        console::log_1(&format!("{} deterministic signing took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(Uint8Array::from(&signature[..]))
     }
-    
-    /// Static method to verify a signature using any public key
+
+    /// Static method to verify a signature using any (suite-prefixed) public key (empty context, for existing callers)
     #[wasm_bindgen]
     pub fn verify_with_key(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, JsValue> {
+        Self::verify_with_key_and_context(public_key, message, signature, &[])
+    }
+
+    /// Static method to verify using an enveloped (kind- and suite-checked) public key and signature
+    #[wasm_bindgen]
+    pub fn verify_with_key_envelope(public_key: &VollyEnvelope, message: &[u8], signature: &VollyEnvelope, context: &[u8]) -> Result<bool, JsValue> {
+        if signature.kind != ArtifactKind::DsaSignature {
+            return Err(JsValue::from_str(&format!("Envelope type mismatch: expected DsaSignature, got {}", signature.kind.name())));
+        }
+        if public_key.suite_id != signature.suite_id {
+            return Err(JsValue::from_str(&format!(
+                "Envelope suite mismatch: public key suite id {} but signature suite id {}",
+                public_key.suite_id, signature.suite_id
+            )));
+        }
+        let public_key = public_key.expect(ArtifactKind::DsaPublicKey, signature.suite_id)?;
+        Self::verify_with_key_and_context(&with_suite_prefix(signature.suite_id, public_key), message, &signature.payload, context)
+    }
+
+    /// Static method to verify a signature using any (suite-prefixed) public key, bound to the given domain-separation context
+    #[wasm_bindgen]
+    pub fn verify_with_key_and_context(public_key: &[u8], message: &[u8], signature: &[u8], context: &[u8]) -> Result<bool, JsValue> {
+        validate_context(context)?;
+        let (suite, public_key) = split_dsa_suite(public_key)?;
         let start_time = js_sys::Date::now();
-        
-        // Parse the public key
-        if public_key.len() != ml_dsa_65::PK_LEN {
-            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", ml_dsa_65::PK_LEN, public_key.len())));
-        }
-        let mut pk_array = [0u8; ml_dsa_65::PK_LEN];
-        pk_array.copy_from_slice(public_key);
-        let pk = <ml_dsa_65::PublicKey as DsaSerDes>::try_from_bytes(pk_array)
-            .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
-        
-        // Parse the signature
-        if signature.len() != ml_dsa_65::SIG_LEN {
-            return Err(JsValue::from_str(&format!("Invalid signature length: expected {}, got {}", ml_dsa_65::SIG_LEN, signature.len())));
-        }
-        let mut sig_array = [0u8; ml_dsa_65::SIG_LEN];
-        sig_array.copy_from_slice(signature);
-        // Verify the signature directly with bytes (fips204 requires a context parameter)
-        let context = b""; // Empty context for general verification
-        let valid = pk.verify(message, &sig_array, context);
-        
+
+        if public_key.len() != suite.pk_len() {
+            return Err(JsValue::from_str(&format!("Invalid public key length: expected {}, got {}", suite.pk_len(), public_key.len())));
+        }
+        if signature.len() != suite.sig_len() {
+            return Err(JsValue::from_str(&format!("Invalid signature length: expected {}, got {}", suite.sig_len(), signature.len())));
+        }
+
+        let valid = match suite {
+            DsaSuite::MlDsa44 => {
+                let mut pk_array = [0u8; ml_dsa_44::PK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let pk = <ml_dsa_44::PublicKey as DsaSerDes>::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let mut sig_array = [0u8; ml_dsa_44::SIG_LEN];
+                sig_array.copy_from_slice(signature);
+                pk.verify(message, &sig_array, context)
+            }
+            DsaSuite::MlDsa65 => {
+                let mut pk_array = [0u8; ml_dsa_65::PK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let pk = <ml_dsa_65::PublicKey as DsaSerDes>::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let mut sig_array = [0u8; ml_dsa_65::SIG_LEN];
+                sig_array.copy_from_slice(signature);
+                pk.verify(message, &sig_array, context)
+            }
+            DsaSuite::MlDsa87 => {
+                let mut pk_array = [0u8; ml_dsa_87::PK_LEN];
+                pk_array.copy_from_slice(public_key);
+                let pk = <ml_dsa_87::PublicKey as DsaSerDes>::try_from_bytes(pk_array)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {:?}", e)))?;
+                let mut sig_array = [0u8; ml_dsa_87::SIG_LEN];
+                sig_array.copy_from_slice(signature);
+                pk.verify(message, &sig_array, context)
+            }
+        };
+
         let end_time = js_sys::Date::now();
-        
-        console::log_1(&format!("Verification took: {:.2}ms", end_time - start_time).into());
-        
+
+        console::log_1(&format!("{} verification took: {:.2}ms", suite.name(), end_time - start_time).into());
+
         Ok(valid)
     }
-    
-    /// Get key sizes for validation
+
+    /// Sign a pre-computed SHA3-256 digest instead of the full message, so large payloads can be
+    /// hashed incrementally in JS instead of copying the whole message into WASM. The effective
+    /// context is suffixed with `PREHASH_CONTEXT_SUFFIX` so this can't be confused with an
+    /// ordinary `sign`/`sign_with_context` call over the same OID-tagged digest bytes. This is a
+    /// pre-hash convenience built on pure ML-DSA signing, not a certified HashML-DSA
+    /// implementation — the underlying signer never flips FIPS 204's internal hash-mode bit.
+    #[wasm_bindgen]
+    pub fn sign_prehash(&self, digest: &[u8], context: &[u8]) -> Result<Uint8Array, JsValue> {
+        self.sign_with_context(&oid_tagged_digest(digest), &prehash_context(context)?)
+    }
+
+    /// Verify a signature produced by `sign_prehash` over the same SHA3-256 digest
+    #[wasm_bindgen]
+    pub fn verify_prehash(public_key: &[u8], digest: &[u8], signature: &[u8], context: &[u8]) -> Result<bool, JsValue> {
+        Self::verify_with_key_and_context(public_key, &oid_tagged_digest(digest), signature, &prehash_context(context)?)
+    }
+
+    /// Get key sizes for validation, defaulting to ML-DSA-65
     #[wasm_bindgen]
     pub fn key_sizes() -> js_sys::Object {
+        Self::key_sizes_for_suite("ML-DSA-65").unwrap()
+    }
+
+    /// Get key sizes for validation for the named suite
+    #[wasm_bindgen]
+    pub fn key_sizes_for_suite(suite: &str) -> Result<js_sys::Object, JsValue> {
+        let suite = DsaSuite::from_name(suite)?;
         let obj = js_sys::Object::new();
-        js_sys::Reflect::set(&obj, &"publicKey".into(), &(ml_dsa_65::PK_LEN as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"secretKey".into(), &(ml_dsa_65::SK_LEN as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"signature".into(), &(ml_dsa_65::SIG_LEN as u32).into()).unwrap();
-        obj
+        js_sys::Reflect::set(&obj, &"suite".into(), &suite.name().into()).unwrap();
+        js_sys::Reflect::set(&obj, &"publicKey".into(), &(suite.pk_len() as u32 + 1).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"secretKey".into(), &(suite.sk_len() as u32 + 1).into()).unwrap();
+        js_sys::Reflect::set(&obj, &"signature".into(), &(suite.sig_len() as u32).into()).unwrap();
+        Ok(obj)
     }
 }
 
 // Utility functions for ML-DSA
 #[wasm_bindgen]
 pub fn get_dsa_algorithm_info() -> js_sys::Object {
+    get_dsa_algorithm_info_for_suite("ML-DSA-65").unwrap()
+}
+
+#[wasm_bindgen]
+pub fn get_dsa_algorithm_info_for_suite(suite: &str) -> Result<js_sys::Object, JsValue> {
+    let suite = DsaSuite::from_name(suite)?;
     let obj = js_sys::Object::new();
-    js_sys::Reflect::set(&obj, &"algorithm".into(), &"ML-DSA-65".into()).unwrap();
+    js_sys::Reflect::set(&obj, &"algorithm".into(), &suite.name().into()).unwrap();
     js_sys::Reflect::set(&obj, &"standard".into(), &"FIPS 204".into()).unwrap();
-    js_sys::Reflect::set(&obj, &"securityLevel".into(), &"Level 3 (192-bit post-quantum)".into()).unwrap();
-    js_sys::Reflect::set(&obj, &"publicKeySize".into(), &(ml_dsa_65::PK_LEN as u32).into()).unwrap();
-    js_sys::Reflect::set(&obj, &"secretKeySize".into(), &(ml_dsa_65::SK_LEN as u32).into()).unwrap();
-    js_sys::Reflect::set(&obj, &"signatureSize".into(), &(ml_dsa_65::SIG_LEN as u32).into()).unwrap();
-    obj
+    js_sys::Reflect::set(&obj, &"securityLevel".into(), &suite.security_level().into()).unwrap();
+    js_sys::Reflect::set(&obj, &"publicKeySize".into(), &(suite.pk_len() as u32 + 1).into()).unwrap();
+    js_sys::Reflect::set(&obj, &"secretKeySize".into(), &(suite.sk_len() as u32 + 1).into()).unwrap();
+    js_sys::Reflect::set(&obj, &"signatureSize".into(), &(suite.sig_len() as u32).into()).unwrap();
+    Ok(obj)
+}
+
+// Run with `wasm-pack test --node` (these exercise the wasm_bindgen-exported API directly,
+// so they need the same JS glue the crate ships to callers).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn hybrid_kem_round_trips_and_binds_the_transcript() {
+        let alice = VollyHybridKEM::new().unwrap();
+        let bob = VollyHybridKEM::new().unwrap();
+
+        let encaps = alice.encapsulate(&bob.public_key().to_vec()).unwrap();
+        let shared = bob.decapsulate(&encaps.ciphertext().to_vec()).unwrap();
+
+        assert_eq!(shared.to_vec(), encaps.shared_secret().to_vec());
+
+        // Two independent encapsulations against the same public key use fresh ephemeral
+        // X25519 keys, so the KDF transcript (and derived session key) must differ each time.
+        let encaps2 = alice.encapsulate(&bob.public_key().to_vec()).unwrap();
+        assert_ne!(encaps.ciphertext().to_vec(), encaps2.ciphertext().to_vec());
+        assert_ne!(encaps.shared_secret().to_vec(), encaps2.shared_secret().to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn hybrid_kem_rejects_a_tampered_ciphertext_transcript() {
+        let alice = VollyHybridKEM::new().unwrap();
+        let bob = VollyHybridKEM::new().unwrap();
+
+        let encaps = alice.encapsulate(&bob.public_key().to_vec()).unwrap();
+        let mut tampered = encaps.ciphertext().to_vec();
+        *tampered.last_mut().unwrap() ^= 0xFF;
+
+        // Flipping a byte of the ephemeral X25519 public key changes the DH output, so the
+        // derived shared secret must not match the untampered transcript.
+        let shared_tampered = bob.decapsulate(&tampered).unwrap();
+        assert_ne!(shared_tampered.to_vec(), encaps.shared_secret().to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn sign_deterministic_is_stable_for_identical_inputs_and_varies_with_the_message() {
+        let dsa = VollyDSA::new().unwrap();
+        let message = b"deterministic signing test vector";
+
+        let sig1 = dsa.sign_deterministic(message).unwrap();
+        let sig2 = dsa.sign_deterministic(message).unwrap();
+        assert_eq!(sig1.to_vec(), sig2.to_vec());
+        assert!(VollyDSA::verify_with_key(&dsa.public_key().to_vec(), message, &sig1.to_vec()).unwrap());
+
+        let other_sig = dsa.sign_deterministic(b"a different message").unwrap();
+        assert_ne!(sig1.to_vec(), other_sig.to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn sign_with_context_binds_the_signature_to_its_context() {
+        let dsa = VollyDSA::new().unwrap();
+        let message = b"a protocol message";
+        let public_key = dsa.public_key().to_vec();
+
+        let sig = dsa.sign_with_context(message, b"protocol-a").unwrap().to_vec();
+        assert!(VollyDSA::verify_with_key_and_context(&public_key, message, &sig, b"protocol-a").unwrap());
+        // A signature bound to one context must not verify under a different context.
+        assert!(!VollyDSA::verify_with_key_and_context(&public_key, message, &sig, b"protocol-b").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn sign_prehash_round_trips_and_differs_from_signing_the_tagged_digest_directly() {
+        let dsa = VollyDSA::new().unwrap();
+        let digest = Sha3_256::digest(b"a large payload hashed incrementally in JS");
+        let public_key = dsa.public_key().to_vec();
+
+        let prehash_sig = dsa.sign_prehash(&digest, &[]).unwrap().to_vec();
+        assert!(VollyDSA::verify_prehash(&public_key, &digest, &prehash_sig, &[]).unwrap());
+
+        // Domain separation: signing the OID-tagged digest directly as a pure message must not
+        // verify as a pre-hash signature over the same digest, and vice versa.
+        let pure_sig = dsa.sign(&oid_tagged_digest(&digest)).unwrap().to_vec();
+        assert_ne!(prehash_sig, pure_sig);
+        assert!(!VollyDSA::verify_prehash(&public_key, &digest, &pure_sig, &[]).unwrap());
+        assert!(!VollyDSA::verify_with_key(&public_key, &oid_tagged_digest(&digest), &prehash_sig).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn envelope_round_trips_through_bytes_and_base64url() {
+        let kem = VollyKEM::new().unwrap();
+        let envelope = kem.public_key_envelope();
+
+        let via_bytes = VollyEnvelope::from_bytes(&envelope.to_bytes().to_vec()).unwrap();
+        assert_eq!(via_bytes.kind(), ArtifactKind::KemPublicKey);
+        assert_eq!(via_bytes.suite_id(), envelope.suite_id());
+        assert_eq!(via_bytes.payload().unwrap().to_vec(), envelope.payload().unwrap().to_vec());
+
+        let via_base64url = VollyEnvelope::from_base64url(&envelope.to_base64url()).unwrap();
+        assert_eq!(via_base64url.payload().unwrap().to_vec(), envelope.payload().unwrap().to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn envelope_payload_getter_refuses_secret_kinds_but_export_payload_allows_it() {
+        let kem = VollyKEM::new().unwrap();
+        let envelope = kem.export_secret_key_envelope();
+
+        assert!(envelope.payload().is_err());
+        // The envelope's own suite-id byte already disambiguates the suite, so its payload is
+        // the bare secret key, unlike export_secret_key()'s suite-prefixed bytes.
+        assert_eq!(envelope.export_payload().to_vec(), kem.export_secret_key().to_vec()[1..].to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn envelope_roundtrips_a_real_decapsulation() {
+        let kem = VollyKEM::new().unwrap();
+        let encaps = kem.encapsulate_envelope(&kem.public_key_envelope()).unwrap();
+        let shared = kem.decapsulate_envelope(&encaps.ciphertext_envelope().unwrap()).unwrap();
+        assert_eq!(shared.to_vec(), encaps.shared_secret().to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn envelope_rejects_a_kind_mismatch() {
+        let kem = VollyKEM::new().unwrap();
+        // A secret-key envelope handed to a method that expects a ciphertext must be rejected,
+        // not silently reinterpreted as one.
+        let err = kem.decapsulate_envelope(&kem.export_secret_key_envelope());
+        assert!(err.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn envelope_rejects_a_suite_mismatch() {
+        let kem_512 = VollyKEM::with_suite("ML-KEM-512").unwrap();
+        let kem_768 = VollyKEM::with_suite("ML-KEM-768").unwrap();
+
+        let encaps = kem_768.encapsulate_envelope(&kem_768.public_key_envelope()).unwrap();
+        let err = kem_512.decapsulate_envelope(&encaps.ciphertext_envelope().unwrap());
+        assert!(err.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn decapsulate_with_key_envelope_round_trips_an_exported_secret_key() {
+        let kem = VollyKEM::new().unwrap();
+        let encaps = kem.encapsulate_envelope(&kem.public_key_envelope()).unwrap();
+
+        let shared = VollyKEM::decapsulate_with_key_envelope(
+            &kem.export_secret_key_envelope(),
+            &encaps.ciphertext_envelope().unwrap(),
+        ).unwrap();
+        assert_eq!(shared.to_vec(), encaps.shared_secret().to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    fn decapsulate_with_key_envelope_rejects_a_kind_mismatch() {
+        let kem = VollyKEM::new().unwrap();
+        let encaps = kem.encapsulate_envelope(&kem.public_key_envelope()).unwrap();
+
+        // A public-key envelope handed in where a secret-key envelope is expected must be
+        // rejected, not silently reinterpreted as key material.
+        let err = VollyKEM::decapsulate_with_key_envelope(
+            &kem.public_key_envelope(),
+            &encaps.ciphertext_envelope().unwrap(),
+        );
+        assert!(err.is_err());
+    }
 }